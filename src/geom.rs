@@ -1,3 +1,4 @@
+use crate::ops::FloatOps;
 use num_traits::{Float, Num};
 use std::fmt::Debug;
 
@@ -81,9 +82,9 @@ impl<R> P2D<R> {
     /// ```
     pub fn distance_between(p1: &P2D<R>, p2: &P2D<R>) -> R
     where
-        R: Float,
+        R: Float + FloatOps,
     {
-        Self::distance_between_squared(p1, p2).sqrt()
+        Self::distance_between_squared(p1, p2).sqrt_op()
     }
 }
 
@@ -166,13 +167,34 @@ where
         }
     }
 }
+impl<R: Copy> Ellipse<R> {
+    /// Center of the ellipse.
+    pub(crate) fn center(&self) -> P2D<R> {
+        P2D::new(self.center.x, self.center.y)
+    }
+
+    /// Radius along the x-axis (either semi-major or semi-minor).
+    pub(crate) fn x_radius(&self) -> R {
+        self.x_radius
+    }
+
+    /// Radius along the y-axis (either semi-major or semi-minor).
+    pub(crate) fn y_radius(&self) -> R {
+        self.y_radius
+    }
+
+    /// Angle between the local x-axis of the ellipse and the global x-axis.
+    pub(crate) fn angle(&self) -> R {
+        self.angle
+    }
+}
 
 /// Triangle.
 pub struct Triangle<R> {
     /// Points in the triangle.
     points: [P2D<R>; 3],
 }
-impl<R: Float> Triangle<R>
+impl<R: Float + FloatOps> Triangle<R>
 where
     R: Debug,
 {
@@ -228,7 +250,12 @@ where
         let s = half * (a + b + c);
 
         // Heron's formula
-        let area = (s * (s - a) * (s - b) * (s - c)).sqrt();
+        let area = (s * (s - a) * (s - b) * (s - c)).sqrt_op();
         area
     }
+
+    /// Points of the triangle, in order.
+    pub(crate) fn points(&self) -> &[P2D<R>; 3] {
+        &self.points
+    }
 }
@@ -0,0 +1,13 @@
+// vim: set colorcolumn=100:
+
+//! Procedurally "squiggly", hand-drawn-looking shapes, built by displacing shape boundaries with
+//! Perlin noise.
+//!
+//! See [`squiggle`] for the noise-displaced outline API, [`perlin`] for the underlying seedable
+//! noise field, [`geom`] for the shape types it displaces, and [`ops`] for the `std`/`libm`-routed
+//! float operations shared by both.
+
+pub mod geom;
+pub mod ops;
+pub mod perlin;
+pub mod squiggle;
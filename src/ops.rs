@@ -0,0 +1,138 @@
+// vim: set colorcolumn=100:
+
+//! Floating-point operations routed through `std` or `libm`.
+//!
+//! `std`'s `floor`/`sqrt`/`sin`/`cos` have unspecified precision and can drift between targets
+//! and compiler versions, which breaks reproducibility of noise fields, triangle areas and
+//! squiggly outlines across platforms. Following bevy_math's approach, enabling the `libm` cargo
+//! feature routes every rounding and transcendental call in this crate through `libm`'s software
+//! implementations instead, giving bit-reproducible results everywhere.
+
+use num_traits::Float;
+
+#[cfg(not(feature = "libm"))]
+mod imp {
+    pub fn floor_f32(x: f32) -> f32 {
+        x.floor()
+    }
+    pub fn floor_f64(x: f64) -> f64 {
+        x.floor()
+    }
+    pub fn sqrt_f32(x: f32) -> f32 {
+        x.sqrt()
+    }
+    pub fn sqrt_f64(x: f64) -> f64 {
+        x.sqrt()
+    }
+    pub fn sin_f32(x: f32) -> f32 {
+        x.sin()
+    }
+    pub fn sin_f64(x: f64) -> f64 {
+        x.sin()
+    }
+    pub fn cos_f32(x: f32) -> f32 {
+        x.cos()
+    }
+    pub fn cos_f64(x: f64) -> f64 {
+        x.cos()
+    }
+}
+
+#[cfg(feature = "libm")]
+mod imp {
+    pub fn floor_f32(x: f32) -> f32 {
+        libm::floorf(x)
+    }
+    pub fn floor_f64(x: f64) -> f64 {
+        libm::floor(x)
+    }
+    pub fn sqrt_f32(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+    pub fn sqrt_f64(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+    pub fn sin_f32(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+    pub fn sin_f64(x: f64) -> f64 {
+        libm::sin(x)
+    }
+    pub fn cos_f32(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+    pub fn cos_f64(x: f64) -> f64 {
+        libm::cos(x)
+    }
+}
+
+/// Extension trait giving generic `Float` code access to the `std`/`libm`-routed operations
+/// above, without each call site needing to match on the concrete float type.
+///
+/// This is `pub` (rather than `pub(crate)`) because it appears in the trait bounds of public
+/// generic functions such as [`crate::perlin::noise`]; it is implemented here for `f32` and
+/// `f64` only.
+pub trait FloatOps: Float {
+    fn floor_op(self) -> Self;
+    fn sqrt_op(self) -> Self;
+    fn sin_cos_op(self) -> (Self, Self);
+}
+
+impl FloatOps for f32 {
+    fn floor_op(self) -> Self {
+        imp::floor_f32(self)
+    }
+
+    fn sqrt_op(self) -> Self {
+        imp::sqrt_f32(self)
+    }
+
+    fn sin_cos_op(self) -> (Self, Self) {
+        (imp::sin_f32(self), imp::cos_f32(self))
+    }
+}
+
+impl FloatOps for f64 {
+    fn floor_op(self) -> Self {
+        imp::floor_f64(self)
+    }
+
+    fn sqrt_op(self) -> Self {
+        imp::sqrt_f64(self)
+    }
+
+    fn sin_cos_op(self) -> (Self, Self) {
+        (imp::sin_f64(self), imp::cos_f64(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `sin_cos_op`/`floor_op`/`sqrt_op` must agree with `std` when the `libm` feature is
+    /// disabled, since `imp` just forwards to `std` in that configuration.
+    #[test]
+    fn float_ops_match_std_test() {
+        assert_eq!(1.75_f32.floor_op(), 1.75_f32.floor());
+        assert_eq!(2.0_f32.sqrt_op(), 2.0_f32.sqrt());
+        assert_eq!(1.25_f32.sin_cos_op(), 1.25_f32.sin_cos());
+
+        assert_eq!(1.75_f64.floor_op(), 1.75_f64.floor());
+        assert_eq!(2.0_f64.sqrt_op(), 2.0_f64.sqrt());
+        assert_eq!(1.25_f64.sin_cos_op(), 1.25_f64.sin_cos());
+    }
+
+    /// Under the `libm` feature, the software implementations should still agree with `std` to
+    /// within a small tolerance (they aren't bit-identical to `std`, which is the whole point).
+    #[cfg(feature = "libm")]
+    #[test]
+    fn float_ops_libm_path_is_close_to_std_test() {
+        assert!((1.25_f32.sqrt_op() - 1.25_f32.sqrt()).abs() < 1e-6);
+        assert!((1.25_f64.sqrt_op() - 1.25_f64.sqrt()).abs() < 1e-12);
+
+        let (s, c) = 1.25_f32.sin_cos_op();
+        let (std_s, std_c) = 1.25_f32.sin_cos();
+        assert!((s - std_s).abs() < 1e-6 && (c - std_c).abs() < 1e-6);
+    }
+}
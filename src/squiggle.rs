@@ -0,0 +1,310 @@
+// vim: set colorcolumn=100:
+
+//! Noise-displaced "squiggly" outlines for [`Geom`] shapes.
+//!
+//! This tessellates a shape's boundary into an ordered polyline, then displaces each boundary
+//! point along its outward normal using [`Perlin`] noise, giving the hand-drawn, "boiling"
+//! pencil-sketch look the crate is named after.
+
+use crate::geom::{Ellipse, Geom, P2D, Triangle};
+use crate::ops::FloatOps;
+use crate::perlin::Perlin;
+use num_traits::Float;
+use std::fmt::Debug;
+
+/// Parameters controlling a noise-displaced squiggly outline.
+///
+/// Bundling these together means callers animating a boiling outline don't have to re-pass the
+/// same handful of numbers to every [`Geom::squiggly_outline`] call.
+pub struct SquiggleParams<R> {
+    /// How far boundary points are displaced along their outward normal, at most.
+    pub amplitude: R,
+    /// How quickly the displacement varies along the boundary's arc length.
+    pub frequency: R,
+    /// Offset added to the noise input before sampling, along the boundary's arc length axis.
+    /// Stepping this per frame animates the outline so it "boils" like a pencil sketch.
+    pub phase: R,
+    /// Offset along the noise field's second axis, so that several outlines sampled from the
+    /// same [`Perlin`] instance don't look identical.
+    pub seed_offset: R,
+    /// Number of points to tessellate the boundary into. Values below 3 are raised to 3.
+    pub samples: usize,
+}
+
+impl<R: Float + FloatOps + Debug> Geom<R> {
+    /// Tessellate this shape's boundary and displace it along its outward normals with Perlin
+    /// noise, producing a hand-drawn "squiggly" outline.
+    ///
+    /// # Arguments
+    ///
+    /// * `perlin` - Noise field to sample the displacement from.
+    /// * `params` - Amplitude, frequency, phase and sample count controlling the outline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use squiggly_shapes::geom::{Ellipse, Geom, P2D};
+    /// use squiggly_shapes::perlin::Perlin;
+    /// use squiggly_shapes::squiggle::SquiggleParams;
+    ///
+    /// let ellipse = Ellipse::new(P2D::new(0.0, 0.0), 10.0, 6.0, 0.0).unwrap();
+    /// let geom = Geom::Ellipse(ellipse);
+    /// let perlin = Perlin::new(42);
+    /// let params = SquiggleParams {
+    ///     amplitude: 0.5,
+    ///     frequency: 0.2,
+    ///     phase: 0.0,
+    ///     seed_offset: 0.0,
+    ///     samples: 64,
+    /// };
+    ///
+    /// let outline = geom.squiggly_outline(&perlin, &params);
+    /// assert_eq!(outline.len(), 64);
+    /// ```
+    pub fn squiggly_outline(&self, perlin: &Perlin, params: &SquiggleParams<R>) -> Vec<P2D<R>> {
+        match self {
+            Geom::Ellipse(ellipse) => ellipse.squiggly_outline(perlin, params),
+            Geom::Triangle(triangle) => triangle.squiggly_outline(perlin, params),
+        }
+    }
+}
+
+impl<R: Float + FloatOps> Ellipse<R> {
+    /// Tessellate this ellipse's boundary and displace it with Perlin noise. See
+    /// [`Geom::squiggly_outline`].
+    pub fn squiggly_outline(&self, perlin: &Perlin, params: &SquiggleParams<R>) -> Vec<P2D<R>> {
+        displace(&boundary_ellipse(self, params.samples), perlin, params)
+    }
+}
+
+impl<R: Float + FloatOps + Debug> Triangle<R> {
+    /// Walk this triangle's edges and displace the resulting boundary with Perlin noise. See
+    /// [`Geom::squiggly_outline`].
+    pub fn squiggly_outline(&self, perlin: &Perlin, params: &SquiggleParams<R>) -> Vec<P2D<R>> {
+        displace(&boundary_triangle(self, params.samples), perlin, params)
+    }
+}
+
+/// Tessellate an ellipse's boundary into `samples` (at least 3) ordered `(point, outward unit
+/// normal)` pairs, sampled parametrically by angle.
+fn boundary_ellipse<R: Float + FloatOps>(
+    ellipse: &Ellipse<R>,
+    samples: usize,
+) -> Vec<(P2D<R>, P2D<R>)> {
+    let samples = samples.max(3);
+    let center = ellipse.center();
+    let x_radius = ellipse.x_radius();
+    let y_radius = ellipse.y_radius();
+    let (sin_angle, cos_angle) = ellipse.angle().sin_cos_op();
+    let two_pi = r::<R>(std::f64::consts::TAU);
+    let n = r::<R>(samples as f64);
+
+    (0..samples)
+        .map(|i| {
+            let t = two_pi * r::<R>(i as f64) / n;
+            let (sin_t, cos_t) = t.sin_cos_op();
+
+            // Point and outward normal direction, in the ellipse's local (unrotated) frame.
+            let local_x = x_radius * cos_t;
+            let local_y = y_radius * sin_t;
+            let normal_x = cos_t / x_radius;
+            let normal_y = sin_t / y_radius;
+            let normal_len = (normal_x * normal_x + normal_y * normal_y).sqrt_op();
+
+            // Rotate into world space and translate to the ellipse's center.
+            let world_x = local_x * cos_angle - local_y * sin_angle + center.x;
+            let world_y = local_x * sin_angle + local_y * cos_angle + center.y;
+            let world_nx = (normal_x * cos_angle - normal_y * sin_angle) / normal_len;
+            let world_ny = (normal_x * sin_angle + normal_y * cos_angle) / normal_len;
+
+            (P2D::new(world_x, world_y), P2D::new(world_nx, world_ny))
+        })
+        .collect()
+}
+
+/// Tessellate a triangle's boundary into `samples` (at least 3) ordered `(point, outward unit
+/// normal)` pairs, by walking its three edges.
+fn boundary_triangle<R: Float + FloatOps + Debug>(
+    triangle: &Triangle<R>,
+    samples: usize,
+) -> Vec<(P2D<R>, P2D<R>)> {
+    let samples = samples.max(3);
+    let points = triangle.points();
+    let three = r::<R>(3.0);
+    let centroid_x = (points[0].x + points[1].x + points[2].x) / three;
+    let centroid_y = (points[0].y + points[1].y + points[2].y) / three;
+
+    // Distribute `samples` points across the three edges as evenly as possible, handing the
+    // remainder to the first few edges, so the total is exactly `samples` rather than always
+    // rounding up to a multiple of 3.
+    let base_samples_per_edge = samples / 3;
+    let extra_edges = samples % 3;
+    let mut boundary = Vec::with_capacity(samples);
+
+    for edge in 0..3 {
+        let a = &points[edge];
+        let b = &points[(edge + 1) % 3];
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+        let edge_len = (dx * dx + dy * dy).sqrt_op();
+
+        // Perpendicular to the edge, flipped outward (away from the centroid) if necessary.
+        let (mut normal_x, mut normal_y) = (-dy / edge_len, dx / edge_len);
+        let mid_x = a.x + dx / r::<R>(2.0);
+        let mid_y = a.y + dy / r::<R>(2.0);
+        if normal_x * (mid_x - centroid_x) + normal_y * (mid_y - centroid_y) < R::zero() {
+            normal_x = -normal_x;
+            normal_y = -normal_y;
+        }
+
+        let samples_per_edge = base_samples_per_edge + usize::from(edge < extra_edges);
+        for i in 0..samples_per_edge {
+            let frac = r::<R>(i as f64) / r::<R>(samples_per_edge as f64);
+            let point = P2D::new(a.x + dx * frac, a.y + dy * frac);
+            boundary.push((point, P2D::new(normal_x, normal_y)));
+        }
+    }
+
+    boundary
+}
+
+/// Displace an ordered boundary along its outward normals, by Perlin noise sampled from each
+/// point's running arc length.
+fn displace<R: Float + FloatOps>(
+    boundary: &[(P2D<R>, P2D<R>)],
+    perlin: &Perlin,
+    params: &SquiggleParams<R>,
+) -> Vec<P2D<R>> {
+    let mut arc_length = R::zero();
+    let mut prev: Option<&P2D<R>> = None;
+
+    boundary
+        .iter()
+        .map(|(point, normal)| {
+            if let Some(prev_point) = prev {
+                arc_length = arc_length + P2D::distance_between(prev_point, point);
+            }
+            prev = Some(point);
+
+            let t = arc_length * params.frequency + params.phase;
+            let displacement = params.amplitude * perlin.noise(t, params.seed_offset, R::zero());
+
+            P2D::new(
+                point.x + normal.x * displacement,
+                point.y + normal.y * displacement,
+            )
+        })
+        .collect()
+}
+
+/// Construct an `R` value from an `f64` literal.
+fn r<R: Float>(x: f64) -> R {
+    R::from(x).expect("Could not construct R value from f64 literal")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_params(samples: usize) -> SquiggleParams<f32> {
+        SquiggleParams {
+            amplitude: 0.5,
+            frequency: 0.2,
+            phase: 0.0,
+            seed_offset: 0.0,
+            samples,
+        }
+    }
+
+    #[test]
+    fn boundary_ellipse_normals_are_unit_length_test() {
+        let ellipse = Ellipse::new(P2D::new(1.0, -2.0), 10.0, 4.0, 0.3).unwrap();
+        for (_, normal) in boundary_ellipse(&ellipse, 32) {
+            let len = (normal.x * normal.x + normal.y * normal.y).sqrt();
+            assert!((len - 1.0).abs() < 1e-4, "normal length = {len}");
+        }
+    }
+
+    #[test]
+    fn boundary_triangle_normals_are_unit_length_and_outward_test() {
+        let triangle =
+            Triangle::new(P2D::new(0.0, 0.0), P2D::new(4.0, 0.0), P2D::new(0.0, 3.0), 1e-3)
+                .unwrap();
+        let centroid = P2D::new(4.0 / 3.0, 1.0);
+
+        for (point, normal) in boundary_triangle(&triangle, 30) {
+            let len = (normal.x * normal.x + normal.y * normal.y).sqrt();
+            assert!((len - 1.0).abs() < 1e-4, "normal length = {len}");
+
+            // The normal should point away from the centroid, not toward it.
+            let to_point_x = point.x - centroid.x;
+            let to_point_y = point.y - centroid.y;
+            assert!(
+                normal.x * to_point_x + normal.y * to_point_y >= 0.0,
+                "normal at ({}, {}) does not point outward from the centroid",
+                point.x,
+                point.y
+            );
+        }
+    }
+
+    #[test]
+    fn squiggly_outline_displaces_along_normal_test() {
+        let ellipse = Ellipse::new(P2D::new(0.0, 0.0), 10.0, 6.0, 0.0).unwrap();
+        let perlin = Perlin::new(42);
+        let params = default_params(64);
+
+        let boundary = boundary_ellipse(&ellipse, params.samples);
+        let outline = displace(&boundary, &perlin, &params);
+
+        assert_eq!(outline.len(), boundary.len());
+        for ((point, normal), displaced) in boundary.iter().zip(outline.iter()) {
+            let dx = displaced.x - point.x;
+            let dy = displaced.y - point.y;
+            let displacement_len = (dx * dx + dy * dy).sqrt();
+            assert!(
+                displacement_len <= params.amplitude + 1e-4,
+                "displacement magnitude {displacement_len} exceeds amplitude"
+            );
+
+            // The displacement must be parallel to the normal (same or opposite direction).
+            let cross = dx * normal.y - dy * normal.x;
+            assert!(
+                cross.abs() < 1e-4,
+                "displacement is not along the normal: cross = {cross}"
+            );
+        }
+    }
+
+    #[test]
+    fn squiggly_outline_triangle_sample_count_test() {
+        let triangle =
+            Triangle::new(P2D::new(0.0, 0.0), P2D::new(4.0, 0.0), P2D::new(0.0, 3.0), 1e-3)
+                .unwrap();
+        let geom = Geom::Triangle(triangle);
+        let perlin = Perlin::new(7);
+        let params = default_params(30);
+
+        let outline = geom.squiggly_outline(&perlin, &params);
+        assert_eq!(outline.len(), 30);
+    }
+
+    /// `samples` need not be a multiple of 3; `boundary_triangle` must still return exactly
+    /// that many points rather than rounding up to the next multiple of 3 per edge.
+    #[test]
+    fn boundary_triangle_sample_count_is_exact_test() {
+        let triangle =
+            Triangle::new(P2D::new(0.0, 0.0), P2D::new(4.0, 0.0), P2D::new(0.0, 3.0), 1e-3)
+                .unwrap();
+
+        for samples in [3, 4, 5, 30, 64, 100] {
+            let boundary = boundary_triangle(&triangle, samples);
+            assert_eq!(
+                boundary.len(),
+                samples,
+                "requested {samples} samples, got {}",
+                boundary.len()
+            );
+        }
+    }
+}